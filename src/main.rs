@@ -1,12 +1,15 @@
-use chrono::Datelike;
+use chrono::{Datelike, FixedOffset, TimeZone};
 use color_eyre::eyre::Context;
 use serde::{Deserialize, Serialize};
 use std::{
     fs::{File, OpenOptions},
     io::Write,
     path::PathBuf,
+    sync::Arc,
+    time::Duration,
 };
 use structopt::StructOpt;
+use tokio::sync::Semaphore;
 
 macro_rules! poss_values {
     ($($value:tt)*) => {
@@ -14,11 +17,13 @@ macro_rules! poss_values {
     };
 }
 
+const TIMINGS_MARKER: &str = "---aoc_runner:timings---";
 const DAY_EXEC_TEMPLATE: &str = r#"use aoc_2020::{problems::{{day}}::execute, DayContext};
 
 fn main() -> color_eyre::Result<()> {
     let mut context = DayContext::load()?;
     execute(&mut context)?;
+    println!("{{timings_marker}}");
     context.report_timings();
     Ok(())
 }"#;
@@ -54,6 +59,10 @@ struct Args {
 #[derive(StructOpt)]
 enum Command {
     Run(RunCommand),
+    Submit(SubmitCommand),
+    Download(DownloadCommand),
+    Read(ReadCommand),
+    All(AllCommand),
     Stub,
 }
 
@@ -67,18 +76,152 @@ struct RunCommand {
     input_dir: PathBuf,
 }
 
+#[derive(StructOpt)]
+struct SubmitCommand {
+    #[structopt(short, long)]
+    year: Option<u16>,
+    #[structopt(short, long, default_value="1", possible_values=&["1", "2"])]
+    part: u8,
+    #[structopt(short, long, default_value = "inputs")]
+    input_dir: PathBuf,
+    #[structopt(short, long)]
+    answer: Option<String>,
+}
+
+#[derive(StructOpt)]
+struct DownloadCommand {
+    #[structopt(short, long)]
+    year: Option<u16>,
+    #[structopt(short, long, default_value = "inputs")]
+    input_dir: PathBuf,
+    #[structopt(long)]
+    all: bool,
+}
+
+#[derive(StructOpt)]
+struct ReadCommand {
+    #[structopt(short, long)]
+    year: Option<u16>,
+}
+
+#[derive(StructOpt)]
+struct AllCommand {
+    #[structopt(short, long)]
+    year: Option<u16>,
+    #[structopt(short, long, default_value = "inputs")]
+    input_dir: PathBuf,
+    #[structopt(short, long, default_value = "1")]
+    time: usize,
+}
+
 #[derive(Serialize, Deserialize)]
 struct Data {
     session: String,
+    #[serde(default)]
+    contact: String,
 }
 
-async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
+fn build_client(contact: &str) -> color_eyre::Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(format!(
+            "aoc_runner (+https://github.com/traxys/aoc_runner) {}",
+            contact
+        ))
+        .build()
+        .with_context(|| "Could not build the HTTP client")
+}
+
+#[derive(Debug)]
+enum WrongHint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug)]
+enum Verdict {
+    Correct,
+    Wrong(Option<WrongHint>),
+    RateLimited(String),
+    AlreadySolved,
+    Unknown(String),
+}
+
+fn parse_verdict(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split("You have ")
+            .nth(1)
+            .and_then(|rest| rest.split(" left").next())
+            .map(|wait| wait.trim().to_string())
+            .unwrap_or_else(|| "some time".to_string());
+        Verdict::RateLimited(wait)
+    } else if body.contains("not the right answer") {
+        let hint = if body.contains("too high") {
+            Some(WrongHint::TooHigh)
+        } else if body.contains("too low") {
+            Some(WrongHint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong(hint)
+    } else if body.contains("already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(body.to_string())
+    }
+}
+
+fn print_verdict(verdict: &Verdict) {
+    use colored::Colorize;
+
+    match verdict {
+        Verdict::Correct => println!("{}", "That's the right answer!".green().bold()),
+        Verdict::Wrong(hint) => {
+            let hint = match hint {
+                Some(WrongHint::TooHigh) => " (too high)",
+                Some(WrongHint::TooLow) => " (too low)",
+                None => "",
+            };
+            println!("{}{}", "That's not the right answer.".red().bold(), hint);
+        }
+        Verdict::RateLimited(wait) => println!(
+            "{} You have {} left to wait.",
+            "You gave an answer too recently.".yellow().bold(),
+            wait
+        ),
+        Verdict::AlreadySolved => println!("{}", "You already solved this one.".cyan().bold()),
+        Verdict::Unknown(body) => {
+            println!("{}", "Could not determine the verdict:".red().bold());
+            println!("{}", body);
+        }
+    }
+}
+
+fn default_year() -> u16 {
+    std::env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or_else(|| chrono::Local::now().year() as u16)
+}
+
+fn load_data() -> color_eyre::Result<Data> {
     let mut data_dir = dirs_next::data_dir().ok_or(color_eyre::eyre::eyre!("No data dir found"))?;
     data_dir.push("aoc_runner.json");
 
-    let data = if !data_dir.exists() {
-        let session = promptly::prompt("Your session value")?;
-        let d = Data { session };
+    let mut data = if !data_dir.exists() {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => promptly::prompt("Your session value")?,
+        };
+        let contact = match std::env::var("AOC_CONTACT") {
+            Ok(contact) => contact,
+            Err(_) => promptly::prompt(
+                "A contact to put in the User-Agent (e.g. an email or a repo URL)",
+            )?,
+        };
+        let d = Data { session, contact };
         serde_json::to_writer_pretty(
             OpenOptions::new()
                 .create(true)
@@ -91,13 +234,75 @@ async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
         .with_context(|| "Could not serialize data")?;
         d
     } else {
-        serde_json::from_reader(
+        let mut data: Data = serde_json::from_reader(
             File::open(&data_dir)
                 .with_context(|| format!("Could not open data file at {:?}", data_dir))?,
         )
-        .with_context(|| "Could not read data file")?
+        .with_context(|| "Could not read data file")?;
+
+        if data.contact.is_empty() {
+            data.contact = match std::env::var("AOC_CONTACT") {
+                Ok(contact) => contact,
+                Err(_) => promptly::prompt(
+                    "A contact to put in the User-Agent (e.g. an email or a repo URL)",
+                )?,
+            };
+            serde_json::to_writer_pretty(
+                OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(&data_dir)
+                    .with_context(|| format!("Could not open data file at {:?}", data_dir))?,
+                &data,
+            )
+            .with_context(|| "Could not serialize data")?;
+        }
+
+        data
     };
 
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        data.session = session;
+    }
+    if let Ok(contact) = std::env::var("AOC_CONTACT") {
+        data.contact = contact;
+    }
+
+    Ok(data)
+}
+
+fn day_unlocked(year: u16, day: u8) -> bool {
+    let est = FixedOffset::west(5 * 3600);
+    let unlock = est.ymd(year as i32, 12, day as u32).and_hms(0, 0, 0);
+    est.from_utc_datetime(&chrono::Utc::now().naive_utc()) >= unlock
+}
+
+async fn fetch_input(
+    client: &reqwest::Client,
+    session: &str,
+    year: u16,
+    day: u8,
+) -> color_eyre::Result<String> {
+    client
+        .get(&format!(
+            "https://adventofcode.com/{}/day/{}/input",
+            year, day
+        ))
+        .header("Cookie", format!("session={}", session))
+        .send()
+        .await
+        .with_context(|| format!("Could not fetch the input for day {} of AoC {}", day, year))?
+        .error_for_status()
+        .with_context(|| format!("Error accessing the input for day {} of AoC {}", day, year))?
+        .text()
+        .await
+        .with_context(|| "Error reading the body of the response")
+}
+
+async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
+    let data = load_data()?;
+
     let day_name = format!("day{}", day);
     let mut input = args.input_dir.clone();
     input.push(&day_name);
@@ -105,23 +310,10 @@ async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
     if !input.exists() {
         let year = args
             .year
-            .unwrap_or_else(|| chrono::Local::now().year() as u16);
-
-        let client = reqwest::Client::new();
-        let body = client
-            .get(&format!(
-                "https://adventofcode.com/{}/day/{}/input",
-                year, day
-            ))
-            .header("Cookie", format!("session={}", data.session))
-            .send()
-            .await
-            .with_context(|| format!("Could not fetch the input for day {} of AoC {}", day, year))?
-            .error_for_status()
-            .with_context(|| format!("Error accessing the input for day {} of AoC {}", day, year))?
-            .text()
-            .await
-            .with_context(|| "Error reading the body of the response")?;
+            .unwrap_or_else(default_year);
+
+        let client = build_client(&data.contact)?;
+        let body = fetch_input(&client, &data.session, year, day).await?;
 
         let mut writer = OpenOptions::new()
             .create(true)
@@ -138,7 +330,10 @@ async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
     if !executable.exists() {
         let reg = handlebars::Handlebars::new();
         let exec_code = reg
-            .render_template(DAY_EXEC_TEMPLATE, &serde_json::json!({ "day": &day_name }))
+            .render_template(
+                DAY_EXEC_TEMPLATE,
+                &serde_json::json!({ "day": &day_name, "timings_marker": TIMINGS_MARKER }),
+            )
             .with_context(|| "Could not render day binary template")?;
         let mut exec_file = OpenOptions::new()
             .create_new(true)
@@ -171,6 +366,273 @@ async fn run(args: RunCommand, day: u8) -> color_eyre::Result<()> {
     Ok(())
 }
 
+async fn download(args: DownloadCommand, day: u8) -> color_eyre::Result<()> {
+    let data = load_data()?;
+    let year = args
+        .year
+        .unwrap_or_else(default_year);
+
+    std::fs::create_dir_all(&args.input_dir)
+        .with_context(|| format!("Could not create the input dir {:?}", args.input_dir))?;
+
+    let days: Vec<u8> = if args.all { (1..=25).collect() } else { vec![day] };
+
+    let client = build_client(&data.contact)?;
+    let semaphore = Arc::new(Semaphore::new(4));
+    let mut tasks = Vec::new();
+
+    for day in days {
+        let day_name = format!("day{}", day);
+        let mut input = args.input_dir.clone();
+        input.push(&day_name);
+
+        if input.exists() || !day_unlocked(year, day) {
+            continue;
+        }
+
+        let client = client.clone();
+        let session = data.session.clone();
+        let semaphore = semaphore.clone();
+
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let body = fetch_input(&client, &session, year, day).await?;
+
+            let mut writer = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .open(&input)
+                .with_context(|| format!("Could not open file at {:?}", input))?;
+            writer
+                .write_all(body.as_bytes())
+                .with_context(|| format!("Could not write to file {:?}", input))?;
+
+            println!("Downloaded {}", day_name);
+            color_eyre::Result::<()>::Ok(())
+        }));
+    }
+
+    for task in tasks {
+        task.await.with_context(|| "A download task panicked")??;
+    }
+
+    Ok(())
+}
+
+fn render_node(node: ego_tree::NodeRef<scraper::Node>, out: &mut String) {
+    match node.value() {
+        scraper::Node::Text(text) => out.push_str(text),
+        scraper::Node::Element(el) => match el.name() {
+            "h2" => {
+                out.push_str("## ");
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push_str("\n\n");
+            }
+            "p" => {
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push_str("\n\n");
+            }
+            "em" | "i" => {
+                out.push('*');
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push('*');
+            }
+            "code" => {
+                let in_pre = node
+                    .parent()
+                    .and_then(|parent| parent.value().as_element())
+                    .map_or(false, |parent| parent.name() == "pre");
+                if in_pre {
+                    for child in node.children() {
+                        render_node(child, out);
+                    }
+                } else {
+                    out.push('`');
+                    for child in node.children() {
+                        render_node(child, out);
+                    }
+                    out.push('`');
+                }
+            }
+            "pre" => {
+                out.push_str("```\n");
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push_str("\n```\n\n");
+            }
+            "li" => {
+                out.push_str("- ");
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push('\n');
+            }
+            "ul" | "ol" => {
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push('\n');
+            }
+            "a" => {
+                let href = el.attr("href").unwrap_or("");
+                out.push('[');
+                for child in node.children() {
+                    render_node(child, out);
+                }
+                out.push_str(&format!("]({})", href));
+            }
+            _ => {
+                for child in node.children() {
+                    render_node(child, out);
+                }
+            }
+        },
+        _ => {}
+    }
+}
+
+fn article_to_markdown(article: scraper::ElementRef) -> String {
+    let mut out = String::new();
+    for child in article.children() {
+        render_node(child, &mut out);
+    }
+    out.trim().to_string()
+}
+
+async fn read(args: ReadCommand, day: u8) -> color_eyre::Result<()> {
+    let data = load_data()?;
+    let year = args.year.unwrap_or_else(default_year);
+
+    let cache_dir = PathBuf::from("puzzles");
+    std::fs::create_dir_all(&cache_dir)
+        .with_context(|| format!("Could not create the cache dir {:?}", cache_dir))?;
+    let mut cache = cache_dir;
+    cache.push(format!("day{}.md", day));
+
+    if cache.exists() {
+        let cached = std::fs::read_to_string(&cache)
+            .with_context(|| format!("Could not read the cache file {:?}", cache))?;
+        if cached.contains("## --- Part Two ---") {
+            println!("{}", cached);
+            return Ok(());
+        }
+    }
+
+    let client = build_client(&data.contact)?;
+    let body = client
+        .get(&format!("https://adventofcode.com/{}/day/{}", year, day))
+        .header("Cookie", format!("session={}", data.session))
+        .send()
+        .await
+        .with_context(|| format!("Could not fetch the puzzle for day {} of AoC {}", day, year))?
+        .error_for_status()
+        .with_context(|| format!("Error accessing the puzzle for day {} of AoC {}", day, year))?
+        .text()
+        .await
+        .with_context(|| "Error reading the body of the response")?;
+
+    let document = scraper::Html::parse_document(&body);
+    let selector = scraper::Selector::parse("article.day-desc")
+        .map_err(|_| color_eyre::eyre::eyre!("Could not build the article selector"))?;
+
+    let markdown = document
+        .select(&selector)
+        .map(article_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    std::fs::write(&cache, &markdown)
+        .with_context(|| format!("Could not write the cache file {:?}", cache))?;
+
+    println!("{}", markdown);
+
+    Ok(())
+}
+
+async fn submit(args: SubmitCommand, day: u8) -> color_eyre::Result<()> {
+    let data = load_data()?;
+    let year = args
+        .year
+        .unwrap_or_else(default_year);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let day_name = format!("day{}", day);
+            let mut input = args.input_dir.clone();
+            input.push(&day_name);
+
+            let output = tokio::process::Command::new("cargo")
+                .args(&[
+                    "run",
+                    "--release",
+                    "--features",
+                    &day_name,
+                    "--bin",
+                    &day_name,
+                    "--",
+                    "--part",
+                    &format!("{}", args.part),
+                    "--input",
+                ])
+                .arg(input)
+                .output()
+                .await
+                .with_context(|| "Could not execute the program")?;
+
+            if !output.status.success() {
+                return Err(color_eyre::eyre::eyre!(
+                    "The program for day {} exited with {}, refusing to guess an answer",
+                    day_name,
+                    output.status
+                ));
+            }
+
+            String::from_utf8(output.stdout)
+                .with_context(|| "The program output was not valid utf-8")?
+                .split(TIMINGS_MARKER)
+                .next()
+                .unwrap_or_default()
+                .lines()
+                .last()
+                .ok_or_else(|| color_eyre::eyre::eyre!("The program did not print an answer"))?
+                .trim()
+                .to_string()
+        }
+    };
+
+    let client = build_client(&data.contact)?;
+    let body = client
+        .post(&format!(
+            "https://adventofcode.com/{}/day/{}/answer",
+            year, day
+        ))
+        .header("Cookie", format!("session={}", data.session))
+        .form(&[("level", format!("{}", args.part)), ("answer", answer)])
+        .send()
+        .await
+        .with_context(|| format!("Could not submit the answer for day {} of AoC {}", day, year))?
+        .error_for_status()
+        .with_context(|| format!("Error submitting the answer for day {} of AoC {}", day, year))?
+        .text()
+        .await
+        .with_context(|| "Error reading the body of the response")?;
+
+    print_verdict(&parse_verdict(&body));
+
+    Ok(())
+}
+
 fn stub(day: u8) -> color_eyre::Result<()> {
     let stub = format!("src/problems/day{}.rs", day);
     let mut stub = OpenOptions::new()
@@ -202,13 +664,204 @@ fn stub(day: u8) -> color_eyre::Result<()> {
     Ok(())
 }
 
+#[derive(Default, Clone, Copy)]
+struct DayTimings {
+    parsing: Duration,
+    part1: Duration,
+    part2: Duration,
+}
+
+struct TimingsSummary {
+    best: DayTimings,
+    mean: DayTimings,
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (amount, unit) = value.split_at(split);
+    let amount: f64 = amount.parse().ok()?;
+    let nanos = match unit.trim() {
+        "ns" => amount,
+        "µs" | "us" => amount * 1_000.0,
+        "ms" => amount * 1_000_000.0,
+        "s" => amount * 1_000_000_000.0,
+        _ => return None,
+    };
+    Some(Duration::from_nanos(nanos as u64))
+}
+
+fn parse_timings(output: &str) -> DayTimings {
+    let mut timings = DayTimings::default();
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("Parsing:") {
+            timings.parsing = parse_duration(value).unwrap_or_default();
+        } else if let Some(value) = line.strip_prefix("Part 1:") {
+            timings.part1 = parse_duration(value).unwrap_or_default();
+        } else if let Some(value) = line.strip_prefix("Part 2:") {
+            timings.part2 = parse_duration(value).unwrap_or_default();
+        }
+    }
+    timings
+}
+
+fn summarize_timings(timings: &[DayTimings]) -> TimingsSummary {
+    let best = DayTimings {
+        parsing: timings.iter().map(|t| t.parsing).min().unwrap_or_default(),
+        part1: timings.iter().map(|t| t.part1).min().unwrap_or_default(),
+        part2: timings.iter().map(|t| t.part2).min().unwrap_or_default(),
+    };
+
+    let count = timings.len() as u32;
+    let mean = DayTimings {
+        parsing: timings.iter().map(|t| t.parsing).sum::<Duration>() / count,
+        part1: timings.iter().map(|t| t.part1).sum::<Duration>() / count,
+        part2: timings.iter().map(|t| t.part2).sum::<Duration>() / count,
+    };
+
+    TimingsSummary { best, mean }
+}
+
+fn format_cell(best: Duration, mean: Duration) -> String {
+    format!("{:.2?} / {:.2?}", best, mean)
+}
+
+fn print_timings_table(year: u16, runs: usize, rows: &[(u8, TimingsSummary)]) {
+    println!(
+        "AoC {} timings (best / mean of {} run{}):",
+        year,
+        runs,
+        if runs == 1 { "" } else { "s" }
+    );
+    println!(
+        "{:<8}{:>22}{:>22}{:>22}",
+        "Day", "Parsing", "Part 1", "Part 2"
+    );
+
+    let mut total_best = Duration::default();
+    let mut total_mean = Duration::default();
+
+    for (day, summary) in rows {
+        println!(
+            "{:<8}{:>22}{:>22}{:>22}",
+            format!("Day {}", day),
+            format_cell(summary.best.parsing, summary.mean.parsing),
+            format_cell(summary.best.part1, summary.mean.part1),
+            format_cell(summary.best.part2, summary.mean.part2),
+        );
+
+        total_best += summary.best.parsing + summary.best.part1 + summary.best.part2;
+        total_mean += summary.mean.parsing + summary.mean.part1 + summary.mean.part2;
+    }
+
+    println!("{:<8}{:>22}", "Total", format_cell(total_best, total_mean));
+}
+
+fn discover_days() -> color_eyre::Result<Vec<u8>> {
+    let mut days: Vec<u8> = std::fs::read_dir("src/bin")
+        .with_context(|| "Could not read src/bin")?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let name = name.to_str()?.to_string();
+            name.strip_prefix("day")?.strip_suffix(".rs")?.parse().ok()
+        })
+        .collect();
+    days.sort_unstable();
+    Ok(days)
+}
+
+async fn run_day_binary(
+    day_name: &str,
+    input: &PathBuf,
+    part: u8,
+) -> color_eyre::Result<Option<String>> {
+    let output = tokio::process::Command::new("cargo")
+        .args(&[
+            "run",
+            "--release",
+            "--features",
+            day_name,
+            "--bin",
+            day_name,
+            "--",
+            "--part",
+            &format!("{}", part),
+            "--input",
+        ])
+        .arg(input)
+        .output()
+        .await
+        .with_context(|| format!("Could not execute {}", day_name))?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    Ok(Some(String::from_utf8(output.stdout).with_context(|| {
+        format!("The output of {} was not valid utf-8", day_name)
+    })?))
+}
+
+async fn all(args: AllCommand) -> color_eyre::Result<()> {
+    let year = args.year.unwrap_or_else(default_year);
+    let runs = args.time.max(1);
+
+    let mut rows = Vec::new();
+    for day in discover_days()? {
+        let day_name = format!("day{}", day);
+        let mut input = args.input_dir.clone();
+        input.push(&day_name);
+
+        let mut timings = Vec::with_capacity(runs);
+        let mut failed = false;
+
+        for _ in 0..runs {
+            // Run each part separately: `--part` selects which part gets
+            // computed (and thus timed), so a single run never reports both.
+            let part_1 = run_day_binary(&day_name, &input, 1).await?;
+            let part_2 = run_day_binary(&day_name, &input, 2).await?;
+
+            let (part_1, part_2) = match (part_1, part_2) {
+                (Some(part_1), Some(part_2)) => (part_1, part_2),
+                _ => {
+                    failed = true;
+                    break;
+                }
+            };
+
+            let mut timing = parse_timings(&part_1);
+            timing.part2 = parse_timings(&part_2).part2;
+            timings.push(timing);
+        }
+
+        if failed || timings.is_empty() {
+            println!("Skipping {}: the binary did not run successfully", day_name);
+            continue;
+        }
+
+        rows.push((day, summarize_timings(&timings)));
+    }
+
+    print_timings_table(year, runs, &rows);
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
+    dotenv::dotenv().ok();
+
     let args = Args::from_args();
     let day = args.day.unwrap_or_else(|| chrono::Local::now().day() as u8);
 
     match args.command {
         Command::Run(command) => run(command, day).await?,
+        Command::Submit(command) => submit(command, day).await?,
+        Command::Download(command) => download(command, day).await?,
+        Command::Read(command) => read(command, day).await?,
+        Command::All(command) => all(command).await?,
         Command::Stub => stub(day)?,
     }
 